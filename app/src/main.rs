@@ -9,7 +9,10 @@ use std::num::ParseIntError;
 use std::rc::Rc;
 use stdweb::traits::*;
 use stdweb::unstable::TryInto;
-use stdweb::web::event::{InputEvent, MouseDownEvent, MouseOverEvent, TouchMove};
+use stdweb::web::event::{
+    BlurEvent, ClickEvent, InputEvent, KeyDownEvent, MouseDownEvent, MouseOverEvent, MouseUpEvent,
+    TouchEnd, TouchMove,
+};
 use stdweb::web::html_element::InputElement;
 use stdweb::web::window;
 use stdweb::web::{document, Element, HtmlElement};
@@ -61,6 +64,7 @@ impl fmt::Display for AppError {
 pub enum RackError {
     OutOfRange(usize, usize),
     NotANumber,
+    InvalidRange,
 }
 
 impl fmt::Display for RackError {
@@ -70,12 +74,31 @@ impl fmt::Display for RackError {
                 write!(f, "Sequence must be between {} and {}.", min, max)
             }
             RackError::NotANumber => write!(f, "Sequence must be a positive integer."),
+            RackError::InvalidRange => write!(f, "Range start must not be after the end."),
         }
     }
 }
 
-fn parse_usize(value: &str) -> Result<usize, ParseIntError> {
-    usize::from_str_radix(value, 10)
+/// A location input is either a single sequence or a `start-end` range.
+enum ParsedSeq {
+    Single(usize),
+    Range(usize, usize),
+}
+
+/// Parse a location input, accepting either a single sequence (`"12"`) or an inclusive
+/// `"start-end"` range (`"12-20"`).
+fn parse_usize(value: &str) -> Result<ParsedSeq, ParseIntError> {
+    match value.find('-') {
+        Some(idx) => {
+            let start = usize::from_str_radix(&value[..idx], 10)?;
+            let end = usize::from_str_radix(&value[idx + 1..], 10)?;
+            Ok(ParsedSeq::Range(start, end))
+        }
+        None => {
+            let seq = usize::from_str_radix(value, 10)?;
+            Ok(ParsedSeq::Single(seq))
+        }
+    }
 }
 
 // InsertPosition taken from webapi module in stdweb
@@ -105,6 +128,15 @@ fn insert_adjacent_element(target: &Element, position: InsertPosition, el: &Elem
     };
 }
 
+/// Direction of travel for keyboard-driven selection movement.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
 /// T4ED racks are stored in this fashion:
 /// ```
 /// 80 32 16
@@ -120,8 +152,21 @@ pub struct T4edRack {
     rack_indicator: Element,
     columns: Element,
     parent: Element,
+    selected: Option<usize>,
+    zoom: f64,
 }
 
+/// Default, minimum and maximum zoom factors for the rack display, and the step applied by a
+/// single increase/decrease operation.
+const ZOOM_DEFAULT: f64 = 1.0;
+const ZOOM_MIN: f64 = 0.5;
+const ZOOM_MAX: f64 = 2.0;
+const ZOOM_STEP: f64 = 0.1;
+
+/// `localStorage` key the chosen zoom factor is persisted under, so it survives the
+/// page-reload reset in `run()`.
+const ZOOM_STORAGE_KEY: &str = "t4ed-rack-zoom";
+
 impl T4edRack {
     #[allow(unused_must_use)]
     pub fn new(parent: &Element) -> Self {
@@ -157,12 +202,21 @@ impl T4edRack {
             locations
         };
 
+        let zoom = window()
+            .local_storage()
+            .get(ZOOM_STORAGE_KEY)
+            .and_then(|v| v.parse::<f64>().ok())
+            .map(|f| f.max(ZOOM_MIN).min(ZOOM_MAX))
+            .unwrap_or(ZOOM_DEFAULT);
+
         T4edRack {
             parent: parent.clone(),
             columns,
             locations,
             dirty_locations: vec![],
             rack_indicator,
+            selected: None,
+            zoom,
         }
     }
 
@@ -180,7 +234,7 @@ impl T4edRack {
             return false;
         }
 
-        let seq = {
+        let rack_seq = {
             if seq > 80 {
                 seq - 80
             } else {
@@ -189,18 +243,118 @@ impl T4edRack {
         };
 
         self.deactivate_all();
-        let seq = seq - 1;
-        if seq < self.locations.len() {
-            self.locations[seq]
+        let rack_seq = rack_seq - 1;
+        if rack_seq < self.locations.len() {
+            self.locations[rack_seq]
                 .class_list()
                 .add("scan-loc__cell--selected");
-            self.dirty_locations.push(seq);
+            self.dirty_locations.push(rack_seq);
+            self.selected = Some(seq);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move the current selection in `direction`, staying within the 1-160 sequence range.
+    ///
+    /// Up/Down step by one but stop at the current column's boundary (columns are 16 cells
+    /// each). Left/Right jump a whole column by stepping 16 at a time. The rack indicator is
+    /// updated the same way `handle_input_change` updates it. Returns the new sequence, or
+    /// `None` if the resulting location could not be highlighted.
+    pub fn move_selection(&mut self, direction: Direction) -> Option<usize> {
+        let current = self.selected.unwrap_or(1);
+        let column_start = ((current - 1) / 16) * 16 + 1;
+        let column_end = column_start + 15;
+
+        let target = match direction {
+            Direction::Up => {
+                if current < column_end {
+                    current + 1
+                } else {
+                    current
+                }
+            }
+            Direction::Down => {
+                if current > column_start {
+                    current - 1
+                } else {
+                    current
+                }
+            }
+            Direction::Left => {
+                if current + 16 <= 160 {
+                    current + 16
+                } else {
+                    current
+                }
+            }
+            Direction::Right => {
+                if current > 16 {
+                    current - 16
+                } else {
+                    current
+                }
+            }
+        };
+
+        if self.highlight_and_set_rack(target) {
+            Some(target)
+        } else {
+            None
+        }
+    }
+
+    /// Highlight `seq` and update the rack indicator to match, as `handle_input_change` does
+    /// for a successful single-sequence lookup.
+    fn highlight_and_set_rack(&mut self, seq: usize) -> bool {
+        if self.highlight_location(seq) {
+            if seq > 80 {
+                self.set_rack_number(2);
+            } else {
+                self.set_rack_number(1);
+            }
             true
         } else {
             false
         }
     }
 
+    /// Highlight every location whose sequence falls within the inclusive `start..=end`
+    /// interval. Both endpoints must fall within the 1-160 range and `start` must not be
+    /// after `end`. Crossing the rack-80 boundary is allowed; the rack indicator then shows
+    /// both rack numbers.
+    #[allow(unused_must_use)]
+    pub fn highlight_range(&mut self, start: usize, end: usize) -> bool {
+        if start == 0 || end == 0 || start > 160 || end > 160 || start > end {
+            return false;
+        }
+
+        self.deactivate_all();
+        for seq in start..=end {
+            let rack_seq = if seq > 80 { seq - 80 } else { seq };
+            let rack_seq = rack_seq - 1;
+            if rack_seq < self.locations.len() {
+                self.locations[rack_seq]
+                    .class_list()
+                    .add("scan-loc__cell--selected");
+                self.dirty_locations.push(rack_seq);
+            }
+        }
+        self.selected = Some(end);
+
+        let start_rack = if start > 80 { 2 } else { 1 };
+        let end_rack = if end > 80 { 2 } else { 1 };
+        if start_rack == end_rack {
+            self.set_rack_number(start_rack);
+        } else {
+            self.rack_indicator
+                .set_text_content(&format!("{}-{}", start_rack, end_rack));
+        }
+
+        true
+    }
+
     #[allow(unused_must_use)]
     pub fn deactivate_all(&mut self) {
         for el in self.dirty_locations.iter() {
@@ -218,6 +372,41 @@ impl T4edRack {
     pub fn columns(&self) -> &Element {
         &self.columns
     }
+
+    /// Scale the rack grid by `factor` (clamped to `ZOOM_MIN..=ZOOM_MAX`), recomputing the
+    /// columns' max-height so the scaled-up display still fits the viewport. The chosen
+    /// factor is persisted to `localStorage` so it survives a page reload.
+    #[allow(unused_must_use)]
+    pub fn apply_zoom(&mut self, factor: f64) {
+        let factor = factor.max(ZOOM_MIN).min(ZOOM_MAX);
+        self.zoom = factor;
+
+        let height = window().inner_height() as f64 / factor;
+        let style = format!(
+            "max-height: {}px; transform: scale({}); transform-origin: top left;",
+            height, factor
+        );
+        self.columns.set_attribute("style", &style);
+        window()
+            .local_storage()
+            .insert(ZOOM_STORAGE_KEY, &factor.to_string());
+        console!(log, "apply zoom = {}", factor);
+    }
+
+    /// Increase the zoom factor by `ZOOM_STEP`, clamped to `ZOOM_MAX`.
+    pub fn increase_zoom(&mut self) {
+        self.apply_zoom(self.zoom + ZOOM_STEP);
+    }
+
+    /// Decrease the zoom factor by `ZOOM_STEP`, clamped to `ZOOM_MIN`.
+    pub fn decrease_zoom(&mut self) {
+        self.apply_zoom(self.zoom - ZOOM_STEP);
+    }
+
+    /// Reset the zoom factor back to `ZOOM_DEFAULT`.
+    pub fn reset_zoom(&mut self) {
+        self.apply_zoom(ZOOM_DEFAULT);
+    }
 }
 
 macro_rules! eq_variant {
@@ -266,6 +455,52 @@ impl ErrorDisplay {
     }
 }
 
+/// Maximum number of committed selections `SelectionHistory` will retain.
+const SELECTION_HISTORY_LIMIT: usize = 100;
+
+/// Bounded undo/redo history of committed single-sequence selections.
+pub struct SelectionHistory {
+    entries: Vec<usize>,
+    current: usize,
+}
+
+impl SelectionHistory {
+    pub fn new() -> Self {
+        SelectionHistory {
+            entries: vec![],
+            current: 0,
+        }
+    }
+
+    /// Commit a newly highlighted sequence, discarding any redo tail left by previous undos.
+    pub fn push(&mut self, seq: usize) {
+        self.entries.truncate(self.current);
+        self.entries.push(seq);
+        if self.entries.len() > SELECTION_HISTORY_LIMIT {
+            self.entries.remove(0);
+        }
+        self.current = self.entries.len();
+    }
+
+    /// Step back to the previous sequence. A no-op at the start of history.
+    pub fn undo(&mut self) -> Option<usize> {
+        if self.current <= 1 {
+            return None;
+        }
+        self.current -= 1;
+        self.entries.get(self.current - 1).cloned()
+    }
+
+    /// Step forward to the next sequence. A no-op at the end of history.
+    pub fn redo(&mut self) -> Option<usize> {
+        if self.current >= self.entries.len() {
+            return None;
+        }
+        self.current += 1;
+        self.entries.get(self.current - 1).cloned()
+    }
+}
+
 fn document_query_selector(query: &str) -> Result<Element, AppError> {
     document()
         .query_selector(query)
@@ -273,11 +508,6 @@ fn document_query_selector(query: &str) -> Result<Element, AppError> {
         .ok_or_else(|| AppError::MissingElement(query.to_owned()))
 }
 
-fn set_max_height(el: &Element, max_px_height: f64) {
-    el.set_attribute("style", &format!("max-height: {}px", max_px_height));
-    console!(log, "set max height = {}", max_px_height);
-}
-
 fn scroll_to_element(el: &Element) {
     let el: HtmlElement = el.clone().try_into().unwrap();
     let rect = el.get_bounding_client_rect();
@@ -291,17 +521,24 @@ fn scroll_to_element(el: &Element) {
     console!(log, "scroll to={}", target);
 }
 
-fn handle_input_change(rack: &mut T4edRack, errors: &mut ErrorDisplay, value: &str, scroll: bool) {
+fn handle_input_change(
+    rack: &mut T4edRack,
+    errors: &mut ErrorDisplay,
+    history: &mut SelectionHistory,
+    value: &str,
+    scroll: bool,
+    commit: bool,
+) {
     match parse_usize(value) {
-        Ok(seq) => {
-            let height = window().inner_height();
+        Ok(ParsedSeq::Single(seq)) => {
             let container = document_query_selector(".scan-loc__location-display").unwrap();
-            set_max_height(&rack.columns, height as f64);
+            rack.apply_zoom(rack.zoom);
             if scroll {
                 scroll_to_element(&rack.columns);
             }
 
             errors.clear_error(RackError::NotANumber);
+            errors.clear_error(RackError::InvalidRange);
             if !rack.highlight_location(seq) {
                 errors.add_error(RackError::OutOfRange(1, 160));
                 rack.clear_rack_number();
@@ -313,11 +550,37 @@ fn handle_input_change(rack: &mut T4edRack, errors: &mut ErrorDisplay, value: &s
                 } else {
                     rack.set_rack_number(1);
                 }
+                if commit {
+                    history.push(seq);
+                }
+            }
+        }
+        Ok(ParsedSeq::Range(start, end)) => {
+            let container = document_query_selector(".scan-loc__location-display").unwrap();
+            rack.apply_zoom(rack.zoom);
+            if scroll {
+                scroll_to_element(&rack.columns);
+            }
+
+            errors.clear_error(RackError::NotANumber);
+            if start > end {
+                errors.add_error(RackError::InvalidRange);
+                rack.clear_rack_number();
+                rack.deactivate_all();
+            } else if !rack.highlight_range(start, end) {
+                errors.clear_error(RackError::InvalidRange);
+                errors.add_error(RackError::OutOfRange(1, 160));
+                rack.clear_rack_number();
+                rack.deactivate_all();
+            } else {
+                errors.clear_error(RackError::InvalidRange);
+                errors.clear_error(RackError::OutOfRange(0, 0));
             }
         }
         Err(_) => {
             rack.clear_rack_number();
             errors.clear_error(RackError::OutOfRange(0, 0));
+            errors.clear_error(RackError::InvalidRange);
             rack.deactivate_all();
             if value == "" {
                 errors.clear_error(RackError::NotANumber);
@@ -328,18 +591,60 @@ fn handle_input_change(rack: &mut T4edRack, errors: &mut ErrorDisplay, value: &s
     }
 }
 
+/// Tracks an in-progress pointer/touch drag across rack cells for range selection. The
+/// anchor is the sequence where the drag started; while active, hovering a cell selects the
+/// span between the anchor and that cell instead of replacing the single selection.
+#[derive(Default)]
+struct DragState {
+    active: bool,
+    anchor: Option<usize>,
+}
+
 /// Contains event listeners for individual cells.
 mod cell_events {
     use super::*;
 
+    /// Highlight the span between `anchor` and `current` (inclusive, order-independent) and
+    /// reflect it in the input box as a `start-end` range. If the span can't be highlighted,
+    /// surface `RackError::OutOfRange` instead of leaving the input text out of sync with
+    /// the visible selection.
+    fn drag_to(
+        app: &Rc<RefCell<T4edRack>>,
+        errors: &Rc<RefCell<ErrorDisplay>>,
+        input: &InputElement,
+        anchor: usize,
+        current: usize,
+    ) {
+        let (start, end) = if anchor <= current {
+            (anchor, current)
+        } else {
+            (current, anchor)
+        };
+        input.set_raw_value(&format!("{}-{}", start, end));
+        let mut app = app.borrow_mut();
+        let mut errors = errors.borrow_mut();
+        if app.highlight_range(start, end) {
+            errors.clear_error(RackError::InvalidRange);
+            errors.clear_error(RackError::OutOfRange(0, 0));
+        } else {
+            errors.add_error(RackError::OutOfRange(1, 160));
+            app.clear_rack_number();
+            app.deactivate_all();
+        }
+    }
+
     pub fn bind_touch(
         cell: &Element,
         app: Rc<RefCell<T4edRack>>,
         errors: Rc<RefCell<ErrorDisplay>>,
+        history: Rc<RefCell<SelectionHistory>>,
+        drag: Rc<RefCell<DragState>>,
         location_picker: &Element,
     ) {
         let app = app.clone();
         let errors = errors.clone();
+        let history = history.clone();
+        let drag = drag.clone();
         let input: InputElement = location_picker.clone().try_into().unwrap();
         cell.add_event_listener(move |ev: TouchMove| {
             let touch = &ev.touches()[0];
@@ -352,10 +657,27 @@ mod cell_events {
                 Some(v) => v,
                 None => return,
             };
-            let mut app = app.borrow_mut();
-            let mut errors = errors.borrow_mut();
-            input.set_raw_value(&raw_value);
-            handle_input_change(&mut app, &mut errors, &raw_value, false);
+            let current = match parse_usize(&raw_value) {
+                Ok(ParsedSeq::Single(seq)) => seq,
+                _ => return,
+            };
+
+            let anchor = {
+                let mut drag = drag.borrow_mut();
+                let anchor = *drag.anchor.get_or_insert(current);
+                drag.active = true;
+                anchor
+            };
+
+            if anchor == current {
+                input.set_raw_value(&raw_value);
+                let mut app = app.borrow_mut();
+                let mut errors = errors.borrow_mut();
+                let mut history = history.borrow_mut();
+                handle_input_change(&mut app, &mut errors, &mut history, &raw_value, false, true);
+            } else {
+                drag_to(&app, &errors, &input, anchor, current);
+            }
         });
     }
 
@@ -363,17 +685,43 @@ mod cell_events {
         cell: &Element,
         app: Rc<RefCell<T4edRack>>,
         errors: Rc<RefCell<ErrorDisplay>>,
+        history: Rc<RefCell<SelectionHistory>>,
+        drag: Rc<RefCell<DragState>>,
         location_picker: &Element,
     ) {
         let app = app.clone();
         let errors = errors.clone();
+        let history = history.clone();
+        let drag = drag.clone();
         let input: InputElement = location_picker.clone().try_into().unwrap();
         cell.add_event_listener(move |ev: MouseOverEvent| {
             let target: Element = ev.target().unwrap().try_into().unwrap();
             let raw_value = target.get_attribute("data-seq").unwrap();
+            let current = match parse_usize(&raw_value) {
+                Ok(ParsedSeq::Single(seq)) => seq,
+                _ => return,
+            };
+
+            let anchor = {
+                let drag = drag.borrow();
+                if drag.active {
+                    drag.anchor
+                } else {
+                    None
+                }
+            };
+
+            if let Some(anchor) = anchor {
+                drag_to(&app, &errors, &input, anchor, current);
+                return;
+            }
+
             input.set_raw_value(&raw_value);
             let (mut app, mut errors) = (app.borrow_mut(), errors.borrow_mut());
-            handle_input_change(&mut app, &mut errors, &raw_value, false);
+            let mut history = history.borrow_mut();
+            // Plain hover should not commit a history entry; only the cells a user actually
+            // passes through while sweeping would otherwise flood the undo stack.
+            handle_input_change(&mut app, &mut errors, &mut history, &raw_value, false, false);
         });
     }
 
@@ -381,17 +729,29 @@ mod cell_events {
         cell: &Element,
         app: Rc<RefCell<T4edRack>>,
         errors: Rc<RefCell<ErrorDisplay>>,
+        history: Rc<RefCell<SelectionHistory>>,
+        drag: Rc<RefCell<DragState>>,
         location_picker: &Element,
     ) {
         let app = app.clone();
         let errors = errors.clone();
+        let history = history.clone();
+        let drag = drag.clone();
         let input: InputElement = location_picker.clone().try_into().unwrap();
         cell.add_event_listener(move |ev: MouseDownEvent| {
             let target: Element = ev.target().unwrap().try_into().unwrap();
             let raw_value = target.get_attribute("data-seq").unwrap();
             input.set_raw_value(&raw_value);
+
+            if let Ok(ParsedSeq::Single(seq)) = parse_usize(&raw_value) {
+                let mut drag = drag.borrow_mut();
+                drag.active = true;
+                drag.anchor = Some(seq);
+            }
+
             let (mut app, mut errors) = (app.borrow_mut(), errors.borrow_mut());
-            handle_input_change(&mut app, &mut errors, &raw_value, false);
+            let mut history = history.borrow_mut();
+            handle_input_change(&mut app, &mut errors, &mut history, &raw_value, false, true);
         });
     }
 }
@@ -403,6 +763,8 @@ fn run() -> Result<(), AppError> {
     let location_picker = mount_point.query(".scan-loc__location-picker")?;
     let input_error_display = mount_point.query(".scan-loc__errors")?;
     let errors = Rc::new(RefCell::new(ErrorDisplay::new(input_error_display)));
+    let history = Rc::new(RefCell::new(SelectionHistory::new()));
+    let drag = Rc::new(RefCell::new(DragState::default()));
 
     // Reset when page load. This is needed in case the user refreshes the page and there is a
     // value remaining in the input box.
@@ -410,19 +772,161 @@ fn run() -> Result<(), AppError> {
         let input: InputElement = location_picker.clone().try_into().unwrap();
         let mut app = app.borrow_mut();
         let mut errors = errors.borrow_mut();
-        handle_input_change(&mut app, &mut errors, &input.raw_value(), true);
+        let mut history = history.borrow_mut();
+        handle_input_change(&mut app, &mut errors, &mut history, &input.raw_value(), true, false);
     }
 
-    // Bind to InputEvent. This will handle manual user input on the input box.
+    // Bind to InputEvent. This will handle manual user input on the input box. Each keystroke
+    // updates the rack but does not commit to history yet - otherwise every intermediate digit
+    // typed on the way to a value would occupy its own undo entry.
     {
         let app = app.clone();
         let errors = errors.clone();
+        let history = history.clone();
         location_picker.add_event_listener(move |ev: InputEvent| {
             let target: InputElement = ev.target().unwrap().try_into().unwrap();
             let raw_value = target.raw_value();
             let mut app = app.borrow_mut();
             let mut errors = errors.borrow_mut();
-            handle_input_change(&mut app, &mut errors, &raw_value, true);
+            let mut history = history.borrow_mut();
+            handle_input_change(&mut app, &mut errors, &mut history, &raw_value, true, false);
+        });
+    }
+
+    // Commit the settled value to history once the user is done editing: either the input
+    // loses focus, or they confirm with Enter (handled alongside the other key bindings below).
+    {
+        let app = app.clone();
+        let errors = errors.clone();
+        let history = history.clone();
+        location_picker.add_event_listener(move |ev: BlurEvent| {
+            let target: InputElement = ev.target().unwrap().try_into().unwrap();
+            let raw_value = target.raw_value();
+            let mut app = app.borrow_mut();
+            let mut errors = errors.borrow_mut();
+            let mut history = history.borrow_mut();
+            handle_input_change(&mut app, &mut errors, &mut history, &raw_value, false, true);
+        });
+    }
+
+    // Bind arrow-key navigation, Enter-to-confirm, Ctrl+Z/Ctrl+Y undo/redo, and Ctrl+=/Ctrl+-/
+    // Ctrl+0 zoom on the mount point.
+    {
+        let app = app.clone();
+        let errors = errors.clone();
+        let history = history.clone();
+        let input: InputElement = location_picker.clone().try_into().unwrap();
+        mount_point.add_event_listener(move |ev: KeyDownEvent| {
+            if ev.ctrl_key() {
+                match ev.key().as_str() {
+                    "z" | "Z" => {
+                        ev.prevent_default();
+                        let mut app = app.borrow_mut();
+                        let mut errors = errors.borrow_mut();
+                        let mut history = history.borrow_mut();
+                        if let Some(seq) = history.undo() {
+                            if app.highlight_and_set_rack(seq) {
+                                input.set_raw_value(&format!("{}", seq));
+                                errors.clear_error(RackError::NotANumber);
+                                errors.clear_error(RackError::OutOfRange(0, 0));
+                            }
+                        }
+                    }
+                    "y" | "Y" => {
+                        ev.prevent_default();
+                        let mut app = app.borrow_mut();
+                        let mut errors = errors.borrow_mut();
+                        let mut history = history.borrow_mut();
+                        if let Some(seq) = history.redo() {
+                            if app.highlight_and_set_rack(seq) {
+                                input.set_raw_value(&format!("{}", seq));
+                                errors.clear_error(RackError::NotANumber);
+                                errors.clear_error(RackError::OutOfRange(0, 0));
+                            }
+                        }
+                    }
+                    "=" | "+" => {
+                        ev.prevent_default();
+                        app.borrow_mut().increase_zoom();
+                    }
+                    "-" => {
+                        ev.prevent_default();
+                        app.borrow_mut().decrease_zoom();
+                    }
+                    "0" => {
+                        ev.prevent_default();
+                        app.borrow_mut().reset_zoom();
+                    }
+                    _ => {}
+                }
+                return;
+            }
+
+            let direction = match ev.key().as_str() {
+                "ArrowUp" => Direction::Up,
+                "ArrowDown" => Direction::Down,
+                "ArrowLeft" => Direction::Left,
+                "ArrowRight" => Direction::Right,
+                "Enter" => {
+                    let mut app = app.borrow_mut();
+                    let mut errors = errors.borrow_mut();
+                    let mut history = history.borrow_mut();
+                    handle_input_change(
+                        &mut app,
+                        &mut errors,
+                        &mut history,
+                        &input.raw_value(),
+                        false,
+                        true,
+                    );
+                    scroll_to_element(&app.columns);
+                    return;
+                }
+                _ => return,
+            };
+
+            ev.prevent_default();
+            let mut app = app.borrow_mut();
+            let mut errors = errors.borrow_mut();
+            if let Some(seq) = app.move_selection(direction) {
+                let raw_value = format!("{}", seq);
+                input.set_raw_value(&raw_value);
+                errors.clear_error(RackError::NotANumber);
+                errors.clear_error(RackError::OutOfRange(0, 0));
+            }
+        });
+    }
+
+    // Bind optional on-screen zoom controls, if the page provides them.
+    if let Some(button) = mount_point.query_selector(".scan-loc__zoom-in").unwrap() {
+        let app = app.clone();
+        button.add_event_listener(move |_: ClickEvent| app.borrow_mut().increase_zoom());
+    }
+    if let Some(button) = mount_point.query_selector(".scan-loc__zoom-out").unwrap() {
+        let app = app.clone();
+        button.add_event_listener(move |_: ClickEvent| app.borrow_mut().decrease_zoom());
+    }
+    if let Some(button) = mount_point.query_selector(".scan-loc__zoom-reset").unwrap() {
+        let app = app.clone();
+        button.add_event_listener(move |_: ClickEvent| app.borrow_mut().reset_zoom());
+    }
+
+    // End any in-progress drag selection when the pointer/touch is released, even if that
+    // happens outside the rack.
+    {
+        let drag = drag.clone();
+        window().add_event_listener(move |_: MouseUpEvent| {
+            let mut drag = drag.borrow_mut();
+            drag.active = false;
+            drag.anchor = None;
+        });
+    }
+    {
+        let drag = drag.clone();
+        window().add_event_listener(move |_: TouchEnd| {
+            let mut drag = drag.borrow_mut();
+            drag.active = false;
+            drag.anchor = None;
         });
     }
 
@@ -430,9 +934,30 @@ fn run() -> Result<(), AppError> {
     let cells = mount_point.query_selector_all(".scan-loc__cell").unwrap();
     for cell in cells.iter() {
         let cell: Element = cell.try_into().unwrap();
-        cell_events::bind_touch(&cell, app.clone(), errors.clone(), &location_picker);
-        cell_events::bind_mouse_over(&cell, app.clone(), errors.clone(), &location_picker);
-        cell_events::bind_mouse_down(&cell, app.clone(), errors.clone(), &location_picker);
+        cell_events::bind_touch(
+            &cell,
+            app.clone(),
+            errors.clone(),
+            history.clone(),
+            drag.clone(),
+            &location_picker,
+        );
+        cell_events::bind_mouse_over(
+            &cell,
+            app.clone(),
+            errors.clone(),
+            history.clone(),
+            drag.clone(),
+            &location_picker,
+        );
+        cell_events::bind_mouse_down(
+            &cell,
+            app.clone(),
+            errors.clone(),
+            history.clone(),
+            drag.clone(),
+            &location_picker,
+        );
     }
 
     Ok(())